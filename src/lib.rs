@@ -4,6 +4,8 @@
 //!
 
 use std::cmp::Ordering;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+use std::thread;
 
 
 /// In-place sorting of a slice of T.
@@ -68,6 +70,669 @@ pub fn quicksort_by<T: PartialOrd, F>(vec: &mut [T], cmp: &F)
 }
 
 
+/// Ranges shorter than this fall back to insertion sort instead of recursing,
+/// which avoids the recursion overhead on the many tiny subslices that
+/// quicksort produces near the leaves.
+const INSERTION_SORT_CUTOFF: usize = 27;
+
+
+/// In-place insertion sort using a custom comparison function.
+///
+/// Used as the base case for the dual-pivot quicksort below; insertion sort is
+/// hard to beat on very short ranges where it is almost linear and allocates
+/// nothing.
+fn insertion_sort_by<T: PartialOrd, F>(vec: &mut [T], cmp: &F)
+    where F: Fn(&T, &T) -> Ordering {
+
+    for i in 1 .. vec.len() {
+        let mut j: usize = i;
+        while j > 0 && cmp(&vec[j - 1], &vec[j]) == Ordering::Greater {
+            vec.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+
+/// In-place dual-pivot sorting of a slice of T.
+///
+/// ```rust
+/// # use ironsort::dual_pivot_quicksort;
+/// let presorted: Vec<u64> = vec![1, 1, 2, 3, 3, 4, 5, 5, 6, 9];
+/// let mut vector: Vec<u64> = vec![3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+/// dual_pivot_quicksort(&mut vector);
+///
+/// assert_eq!(vector, presorted.as_slice());
+/// ```
+#[inline]
+pub fn dual_pivot_quicksort<T: Ord>(vec: &mut [T]) {
+    dual_pivot_quicksort_by(vec, &|x, y| x.cmp(y))
+}
+
+
+/// In-place dual-pivot sorting of a slice of T using a custom comparison function.
+///
+/// Unlike `quicksort_by` this partitions around two pivots per pass, producing
+/// three regions instead of two, and stops recursing on ranges shorter than
+/// `INSERTION_SORT_CUTOFF` in favour of a plain insertion sort.
+///
+/// ```rust
+/// # use ironsort::dual_pivot_quicksort_by;
+/// # use std::cmp::Ordering;
+///
+/// let presorted: Vec<u64> = vec![9, 6, 5, 5, 4, 3, 3, 2, 1, 1];
+/// let mut vector: Vec<u64> = vec![3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+///
+/// dual_pivot_quicksort_by(&mut vector, &|x, y| x.cmp(y).reverse());
+///
+/// assert_eq!(vector, presorted.as_slice());
+/// ```
+pub fn dual_pivot_quicksort_by<T: PartialOrd, F>(vec: &mut [T], cmp: &F)
+    where F: Fn(&T, &T) -> Ordering {
+
+    let len: usize = vec.len();
+    if len <= 1 {
+        return;
+    }
+    if len < INSERTION_SORT_CUTOFF {
+        insertion_sort_by(vec, cmp);
+        return;
+    }
+
+    let left: usize = 0;
+    let right: usize = len - 1;
+
+    // pivot1 = vec[left], pivot2 = vec[right], with pivot1 <= pivot2.
+    if cmp(&vec[left], &vec[right]) == Ordering::Greater {
+        vec.swap(left, right);
+    }
+
+    let mut less: usize = left + 1;
+    let mut greater: usize = right - 1;
+    let mut k: usize = left + 1;
+
+    while k <= greater {
+        if cmp(&vec[k], &vec[left]) == Ordering::Less {
+            vec.swap(k, less);
+            less += 1;
+        } else if cmp(&vec[k], &vec[right]) != Ordering::Less {
+            while k < greater && cmp(&vec[greater], &vec[right]) != Ordering::Less {
+                greater -= 1;
+            }
+            vec.swap(k, greater);
+            greater -= 1;
+            if cmp(&vec[k], &vec[left]) == Ordering::Less {
+                vec.swap(k, less);
+                less += 1;
+            }
+        }
+        k += 1;
+    }
+
+    less -= 1;
+    greater += 1;
+    vec.swap(left, less);
+    vec.swap(right, greater);
+
+    dual_pivot_quicksort_by(&mut vec[0..less], cmp);
+    dual_pivot_quicksort_by(&mut vec[less+1..greater], cmp);
+    dual_pivot_quicksort_by(&mut vec[greater+1..], cmp);
+}
+
+
+/// In-place three-way sorting of a slice of T.
+///
+/// ```rust
+/// # use ironsort::quicksort3;
+/// let presorted: Vec<u64> = vec![1, 1, 2, 3, 3, 4, 5, 5, 6, 9];
+/// let mut vector: Vec<u64> = vec![3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+/// quicksort3(&mut vector);
+///
+/// assert_eq!(vector, presorted.as_slice());
+/// ```
+#[inline]
+pub fn quicksort3<T: Ord>(vec: &mut [T]) {
+    quicksort3_by(vec, &|x, y| x.cmp(y))
+}
+
+
+/// In-place three-way (Dutch national flag) sorting of a slice of T using a
+/// custom comparison function.
+///
+/// Partitions the range into elements below, equal to, and above the pivot in a
+/// single scan; the equal band is never revisited, so inputs with only O(k)
+/// distinct keys sort in roughly O(n log k) time.
+///
+/// ```rust
+/// # use ironsort::quicksort3_by;
+/// # use std::cmp::Ordering;
+///
+/// let presorted: Vec<u64> = vec![9, 6, 5, 5, 4, 3, 3, 2, 1, 1];
+/// let mut vector: Vec<u64> = vec![3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+///
+/// quicksort3_by(&mut vector, &|x, y| x.cmp(y).reverse());
+///
+/// assert_eq!(vector, presorted.as_slice());
+/// ```
+pub fn quicksort3_by<T: PartialOrd, F>(vec: &mut [T], cmp: &F)
+    where F: Fn(&T, &T) -> Ordering {
+
+    let len: usize = vec.len();
+    if len <= 1 {
+        return;
+    }
+
+    vec.swap(0, len / 2);
+
+    // Invariant: vec[0..lt] < pivot, vec[lt..i] == pivot, vec[gt+1..] > pivot.
+    // The pivot value always sits at vec[lt], so we compare against it there.
+    let mut lt: usize = 0;
+    let mut gt: usize = len - 1;
+    let mut i: usize = 1;
+
+    while i <= gt {
+        match cmp(&vec[i], &vec[lt]) {
+            Ordering::Less => {
+                vec.swap(lt, i);
+                lt += 1;
+                i += 1;
+            }
+            Ordering::Greater => {
+                vec.swap(i, gt);
+                gt -= 1;
+            }
+            Ordering::Equal => {
+                i += 1;
+            }
+        }
+    }
+
+    quicksort3_by(&mut vec[0..lt], cmp);
+    quicksort3_by(&mut vec[gt+1..], cmp);
+}
+
+
+/// Floor of the base-2 logarithm of `n`, used to derive the introsort
+/// recursion depth limit. `log2_floor(0)` and `log2_floor(1)` are both 0.
+fn log2_floor(mut n: usize) -> usize {
+    let mut result: usize = 0;
+    while n > 1 {
+        n >>= 1;
+        result += 1;
+    }
+    result
+}
+
+
+/// Sift the element at `root` down into the max-heap occupying `vec[0..end]`,
+/// where "max" is the element the comparison function orders as `Greater`.
+fn sift_down_by<T: PartialOrd, F>(vec: &mut [T], mut root: usize, end: usize, cmp: &F)
+    where F: Fn(&T, &T) -> Ordering {
+
+    loop {
+        let mut largest: usize = root;
+        let left: usize = 2 * root + 1;
+        let right: usize = 2 * root + 2;
+
+        if left < end && cmp(&vec[left], &vec[largest]) == Ordering::Greater {
+            largest = left;
+        }
+        if right < end && cmp(&vec[right], &vec[largest]) == Ordering::Greater {
+            largest = right;
+        }
+
+        if largest == root {
+            break;
+        }
+
+        vec.swap(root, largest);
+        root = largest;
+    }
+}
+
+
+/// In-place heapsort using a custom comparison function.
+///
+/// Used as the introsort fallback once a branch exceeds its recursion depth
+/// limit; unlike quicksort it is O(n log n) in the worst case and needs no
+/// extra stack.
+fn heapsort_by<T: PartialOrd, F>(vec: &mut [T], cmp: &F)
+    where F: Fn(&T, &T) -> Ordering {
+
+    let len: usize = vec.len();
+    if len <= 1 {
+        return;
+    }
+
+    let mut start: usize = len / 2;
+    while start > 0 {
+        start -= 1;
+        sift_down_by(vec, start, len, cmp);
+    }
+
+    let mut end: usize = len;
+    while end > 1 {
+        end -= 1;
+        vec.swap(0, end);
+        sift_down_by(vec, 0, end, cmp);
+    }
+}
+
+
+/// Introspective sorting of a slice of T.
+///
+/// ```rust
+/// # use ironsort::introsort;
+/// let presorted: Vec<u64> = vec![1, 1, 2, 3, 3, 4, 5, 5, 6, 9];
+/// let mut vector: Vec<u64> = vec![3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+/// introsort(&mut vector);
+///
+/// assert_eq!(vector, presorted.as_slice());
+/// ```
+#[inline]
+pub fn introsort<T: Ord>(vec: &mut [T]) {
+    introsort_by(vec, &|x, y| x.cmp(y))
+}
+
+
+/// Introspective sorting of a slice of T using a custom comparison function.
+///
+/// Sorts like `quicksort_by` in the common case, but tracks a recursion depth
+/// limit of `2 * floor(log2(len))` and switches any branch that exceeds it to
+/// heapsort. This caps the worst case at O(n log n) and bounds stack depth
+/// while keeping quicksort's fast average behavior.
+///
+/// ```rust
+/// # use ironsort::introsort_by;
+/// # use std::cmp::Ordering;
+///
+/// let presorted: Vec<u64> = vec![9, 6, 5, 5, 4, 3, 3, 2, 1, 1];
+/// let mut vector: Vec<u64> = vec![3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+///
+/// introsort_by(&mut vector, &|x, y| x.cmp(y).reverse());
+///
+/// assert_eq!(vector, presorted.as_slice());
+/// ```
+pub fn introsort_by<T: PartialOrd, F>(vec: &mut [T], cmp: &F)
+    where F: Fn(&T, &T) -> Ordering {
+
+    let len: usize = vec.len();
+    if len <= 1 {
+        return;
+    }
+
+    let depth_limit: usize = 2 * log2_floor(len);
+    introsort_rec(vec, cmp, depth_limit);
+}
+
+
+/// The recursive core of `introsort_by`, threading the remaining recursion
+/// budget `depth_limit` down each branch.
+fn introsort_rec<T: PartialOrd, F>(vec: &mut [T], cmp: &F, depth_limit: usize)
+    where F: Fn(&T, &T) -> Ordering {
+
+    let len: usize = vec.len();
+    if len <= 1 {
+        return;
+    }
+
+    if depth_limit == 0 {
+        heapsort_by(vec, cmp);
+        return;
+    }
+
+    let pivot: usize = 0;
+    vec.swap(pivot, len / 2);
+
+    let mut left: usize = 0;
+    let mut right: usize = vec.len() - 1;
+
+    while left < right {
+        while left < len && cmp(&vec[left], &vec[pivot]) != Ordering::Greater {
+            left += 1
+        }
+        while cmp(&vec[right], &vec[pivot]) == Ordering::Greater {
+            right -= 1
+        }
+
+        if left < right {
+            vec.swap(left, right);
+        }
+    }
+
+    vec.swap(pivot, right);
+    introsort_rec(&mut vec[0..right], cmp, depth_limit - 1);
+    introsort_rec(&mut vec[right+1..], cmp, depth_limit - 1);
+}
+
+
+/// A tiny xorshift64 PRNG, embedded so the randomized pivot path pulls in no
+/// external rng dependency. Seeded per call from the slice itself, which is
+/// cheap and varies enough between invocations to defeat structured inputs.
+struct XorShift64 {
+    state: u64,
+}
+
+impl XorShift64 {
+    fn new(seed: u64) -> XorShift64 {
+        // A zero state would be a fixed point, so force a non-zero seed.
+        XorShift64 { state: seed | 1 }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut x: u64 = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x
+    }
+
+    /// A value in `0..n`; `n` must be non-zero.
+    fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+}
+
+
+/// In-place sorting of a slice of T with a randomized pivot.
+///
+/// ```rust
+/// # use ironsort::quicksort_randomized;
+/// let presorted: Vec<u64> = vec![1, 1, 2, 3, 3, 4, 5, 5, 6, 9];
+/// let mut vector: Vec<u64> = vec![3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+/// quicksort_randomized(&mut vector);
+///
+/// assert_eq!(vector, presorted.as_slice());
+/// ```
+#[inline]
+pub fn quicksort_randomized<T: Ord>(vec: &mut [T]) {
+    quicksort_randomized_by(vec, &|x, y| x.cmp(y))
+}
+
+
+/// In-place sorting of a slice of T with a randomized pivot using a custom
+/// comparison function.
+///
+/// Each recursive range draws its pivot uniformly at random, which makes the
+/// expected running time O(n log n) regardless of how the input is arranged.
+///
+/// ```rust
+/// # use ironsort::quicksort_randomized_by;
+/// # use std::cmp::Ordering;
+///
+/// let presorted: Vec<u64> = vec![9, 6, 5, 5, 4, 3, 3, 2, 1, 1];
+/// let mut vector: Vec<u64> = vec![3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+///
+/// quicksort_randomized_by(&mut vector, &|x, y| x.cmp(y).reverse());
+///
+/// assert_eq!(vector, presorted.as_slice());
+/// ```
+pub fn quicksort_randomized_by<T: PartialOrd, F>(vec: &mut [T], cmp: &F)
+    where F: Fn(&T, &T) -> Ordering {
+
+    let seed: u64 = (vec.len() as u64).wrapping_mul(0x9E3779B97F4A7C15)
+        ^ (vec.as_ptr() as usize as u64);
+    let mut rng = XorShift64::new(seed);
+    quicksort_randomized_rec(vec, cmp, &mut rng);
+}
+
+
+/// The recursive core of `quicksort_randomized_by`, threading the per-call PRNG
+/// down each branch.
+fn quicksort_randomized_rec<T: PartialOrd, F>(vec: &mut [T], cmp: &F, rng: &mut XorShift64)
+    where F: Fn(&T, &T) -> Ordering {
+
+    let len: usize = vec.len();
+    if len <= 1 {
+        return;
+    }
+
+    let pivot: usize = 0;
+    vec.swap(pivot, rng.below(len));
+
+    let mut left: usize = 0;
+    let mut right: usize = vec.len() - 1;
+
+    while left < right {
+        while left < len && cmp(&vec[left], &vec[pivot]) != Ordering::Greater {
+            left += 1
+        }
+        while cmp(&vec[right], &vec[pivot]) == Ordering::Greater {
+            right -= 1
+        }
+
+        if left < right {
+            vec.swap(left, right);
+        }
+    }
+
+    vec.swap(pivot, right);
+    quicksort_randomized_rec(&mut vec[0..right], cmp, rng);
+    quicksort_randomized_rec(&mut vec[right+1..], cmp, rng);
+}
+
+
+/// Index of the median of `vec[0]`, `vec[len/2]` and `vec[len-1]` under `cmp`.
+fn median3_index<T: PartialOrd, F>(vec: &[T], cmp: &F) -> usize
+    where F: Fn(&T, &T) -> Ordering {
+
+    let a: usize = 0;
+    let b: usize = vec.len() / 2;
+    let c: usize = vec.len() - 1;
+
+    if cmp(&vec[a], &vec[b]) == Ordering::Less {
+        if cmp(&vec[b], &vec[c]) != Ordering::Greater {
+            b
+        } else if cmp(&vec[a], &vec[c]) == Ordering::Less {
+            c
+        } else {
+            a
+        }
+    } else {
+        if cmp(&vec[a], &vec[c]) != Ordering::Greater {
+            a
+        } else if cmp(&vec[b], &vec[c]) == Ordering::Less {
+            c
+        } else {
+            b
+        }
+    }
+}
+
+
+/// In-place sorting of a slice of T with a median-of-three pivot.
+///
+/// ```rust
+/// # use ironsort::quicksort_median3;
+/// let presorted: Vec<u64> = vec![1, 1, 2, 3, 3, 4, 5, 5, 6, 9];
+/// let mut vector: Vec<u64> = vec![3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+/// quicksort_median3(&mut vector);
+///
+/// assert_eq!(vector, presorted.as_slice());
+/// ```
+#[inline]
+pub fn quicksort_median3<T: Ord>(vec: &mut [T]) {
+    quicksort_median3_by(vec, &|x, y| x.cmp(y))
+}
+
+
+/// In-place sorting of a slice of T with a median-of-three pivot using a custom
+/// comparison function.
+///
+/// Choosing the median of the first, middle and last elements avoids the
+/// quadratic behavior `quicksort_by` suffers on organ-pipe inputs, without any
+/// randomness.
+///
+/// ```rust
+/// # use ironsort::quicksort_median3_by;
+/// # use std::cmp::Ordering;
+///
+/// let presorted: Vec<u64> = vec![9, 6, 5, 5, 4, 3, 3, 2, 1, 1];
+/// let mut vector: Vec<u64> = vec![3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+///
+/// quicksort_median3_by(&mut vector, &|x, y| x.cmp(y).reverse());
+///
+/// assert_eq!(vector, presorted.as_slice());
+/// ```
+pub fn quicksort_median3_by<T: PartialOrd, F>(vec: &mut [T], cmp: &F)
+    where F: Fn(&T, &T) -> Ordering {
+
+    let len: usize = vec.len();
+    if len <= 1 {
+        return;
+    }
+
+    let pivot: usize = 0;
+    vec.swap(pivot, median3_index(vec, cmp));
+
+    let mut left: usize = 0;
+    let mut right: usize = vec.len() - 1;
+
+    while left < right {
+        while left < len && cmp(&vec[left], &vec[pivot]) != Ordering::Greater {
+            left += 1
+        }
+        while cmp(&vec[right], &vec[pivot]) == Ordering::Greater {
+            right -= 1
+        }
+
+        if left < right {
+            vec.swap(left, right);
+        }
+    }
+
+    vec.swap(pivot, right);
+    quicksort_median3_by(&mut vec[0..right], cmp);
+    quicksort_median3_by(&mut vec[right+1..], cmp);
+}
+
+
+/// Slices at or below this length are sorted sequentially; above it a branch
+/// may be handed to another worker. Large enough that the scheduling cost is
+/// amortised over real work.
+const PARALLEL_THRESHOLD: usize = 4096;
+
+
+/// Try to claim one worker from the shared budget, returning `true` on success.
+/// Used to bound the number of live threads so deeply recursive splits don't
+/// oversubscribe the CPU.
+fn claim_worker(budget: &AtomicUsize) -> bool {
+    let mut current: usize = budget.load(AtomicOrdering::Acquire);
+    while current > 0 {
+        match budget.compare_exchange_weak(
+            current,
+            current - 1,
+            AtomicOrdering::AcqRel,
+            AtomicOrdering::Acquire,
+        ) {
+            Ok(_) => return true,
+            Err(actual) => current = actual,
+        }
+    }
+    false
+}
+
+
+/// In-place parallel sorting of a slice of T.
+///
+/// ```rust
+/// # use ironsort::par_quicksort;
+/// let presorted: Vec<u64> = vec![1, 1, 2, 3, 3, 4, 5, 5, 6, 9];
+/// let mut vector: Vec<u64> = vec![3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+/// par_quicksort(&mut vector);
+///
+/// assert_eq!(vector, presorted.as_slice());
+/// ```
+#[inline]
+pub fn par_quicksort<T: Ord + Send>(vec: &mut [T]) {
+    par_quicksort_by(vec, &|x, y| x.cmp(y))
+}
+
+
+/// In-place parallel sorting of a slice of T using a custom comparison function.
+///
+/// After partitioning, the two halves obtained via `split_at_mut` are disjoint
+/// and may be sorted concurrently; a bounded worker budget keeps the number of
+/// live threads near the available parallelism. Ranges at or below
+/// `PARALLEL_THRESHOLD` fall back to the sequential `quicksort_by`.
+///
+/// ```rust
+/// # use ironsort::par_quicksort_by;
+/// # use std::cmp::Ordering;
+///
+/// let presorted: Vec<u64> = vec![9, 6, 5, 5, 4, 3, 3, 2, 1, 1];
+/// let mut vector: Vec<u64> = vec![3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+///
+/// par_quicksort_by(&mut vector, &|x, y| x.cmp(y).reverse());
+///
+/// assert_eq!(vector, presorted.as_slice());
+/// ```
+pub fn par_quicksort_by<T, F>(vec: &mut [T], cmp: &F)
+    where T: PartialOrd + Send, F: Fn(&T, &T) -> Ordering + Sync {
+
+    let workers: usize = thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1);
+    // One thread is already at work (the caller), so the budget counts the
+    // additional workers that may be spawned alongside it.
+    let budget = AtomicUsize::new(workers.saturating_sub(1));
+    par_quicksort_rec(vec, cmp, &budget);
+}
+
+
+/// The recursive core of `par_quicksort_by`, sharing the worker `budget` across
+/// every branch.
+fn par_quicksort_rec<T, F>(vec: &mut [T], cmp: &F, budget: &AtomicUsize)
+    where T: PartialOrd + Send, F: Fn(&T, &T) -> Ordering + Sync {
+
+    let len: usize = vec.len();
+    if len <= 1 {
+        return;
+    }
+    if len <= PARALLEL_THRESHOLD {
+        quicksort_by(vec, cmp);
+        return;
+    }
+
+    let pivot: usize = 0;
+    vec.swap(pivot, len / 2);
+
+    let mut left: usize = 0;
+    let mut right: usize = vec.len() - 1;
+
+    while left < right {
+        while left < len && cmp(&vec[left], &vec[pivot]) != Ordering::Greater {
+            left += 1
+        }
+        while cmp(&vec[right], &vec[pivot]) == Ordering::Greater {
+            right -= 1
+        }
+
+        if left < right {
+            vec.swap(left, right);
+        }
+    }
+
+    vec.swap(pivot, right);
+
+    let (lower, upper) = vec.split_at_mut(right);
+    // `upper[0]` is the pivot in its final position; only the tail needs sorting.
+    let (_pivot, higher) = upper.split_first_mut().unwrap();
+
+    if claim_worker(budget) {
+        thread::scope(|scope| {
+            scope.spawn(|| par_quicksort_rec(lower, cmp, budget));
+            par_quicksort_rec(higher, cmp, budget);
+        });
+        budget.fetch_add(1, AtomicOrdering::AcqRel);
+    } else {
+        par_quicksort_rec(lower, cmp, budget);
+        par_quicksort_rec(higher, cmp, budget);
+    }
+}
+
+
 #[cfg(test)]
 extern crate rand;
 
@@ -119,4 +784,104 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_dual_pivot_random() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0u64 .. 10_000u64 {
+            let len: usize = rng.gen();
+            let mut vector = rng.gen_iter()
+                                .take((len % 128) + 1)
+                                .collect::<Vec<usize>>();
+            dual_pivot_quicksort(&mut vector);
+
+            for i in 0 .. vector.len() - 1 {
+                assert!(vector[i] <= vector[i + 1])
+            }
+        }
+    }
+
+    #[test]
+    fn test_introsort_random() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0u64 .. 10_000u64 {
+            let len: usize = rng.gen();
+            let mut vector = rng.gen_iter()
+                                .take((len % 128) + 1)
+                                .collect::<Vec<usize>>();
+            introsort(&mut vector);
+
+            for i in 0 .. vector.len() - 1 {
+                assert!(vector[i] <= vector[i + 1])
+            }
+        }
+    }
+
+    #[test]
+    fn test_quicksort3_many_duplicates() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0u64 .. 10_000u64 {
+            let len: usize = rng.gen();
+            let mut vector = rng.gen_iter()
+                                .map(|x: usize| x % 8)
+                                .take((len % 128) + 1)
+                                .collect::<Vec<usize>>();
+            quicksort3(&mut vector);
+
+            for i in 0 .. vector.len() - 1 {
+                assert!(vector[i] <= vector[i + 1])
+            }
+        }
+    }
+
+    #[test]
+    fn test_pivot_strategies_random() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0u64 .. 10_000u64 {
+            let len: usize = rng.gen();
+            let original = rng.gen_iter()
+                              .take((len % 128) + 1)
+                              .collect::<Vec<usize>>();
+
+            let mut randomized = original.clone();
+            quicksort_randomized(&mut randomized);
+
+            let mut median3 = original.clone();
+            quicksort_median3(&mut median3);
+
+            for i in 0 .. original.len() - 1 {
+                assert!(randomized[i] <= randomized[i + 1]);
+                assert!(median3[i] <= median3[i + 1]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_median3_on_sorted_input() {
+        let mut vector: Vec<u64> = (0 .. 1000u64).collect();
+        quicksort_median3(&mut vector);
+
+        for i in 0 .. vector.len() - 1 {
+            assert!(vector[i] <= vector[i + 1])
+        }
+    }
+
+    #[test]
+    fn test_par_large() {
+        let mut rng = rand::thread_rng();
+
+        // Long enough to cross PARALLEL_THRESHOLD and actually spawn workers.
+        let mut vector = rng.gen_iter()
+                            .take(100_000)
+                            .collect::<Vec<usize>>();
+        par_quicksort(&mut vector);
+
+        for i in 0 .. vector.len() - 1 {
+            assert!(vector[i] <= vector[i + 1])
+        }
+    }
 }